@@ -1,7 +1,9 @@
 
+pub mod resolver;
 
 use regex::Regex;
 use hickory_proto::rr;
+use idna;
 
 use std::fmt::Display;
 use std::sync::OnceLock;
@@ -56,10 +58,28 @@ fn is_valid_dns_host(host: &str) -> bool {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ResolvedAddrs {
     v4: Vec<std::net::Ipv4Addr>,
+    v4_ttl: Vec<u32>,
     v6: Vec<std::net::Ipv6Addr>,
+    v6_ttl: Vec<u32>,
 }
 
 impl ResolvedAddrs {
+    /// Builds a `ResolvedAddrs` from per-record `(address, ttl)` pairs, as
+    /// produced by [`resolver::Resolver`](crate::dns::resolver::Resolver).
+    pub(crate) fn from_records(
+        v4: Vec<(std::net::Ipv4Addr, u32)>,
+        v6: Vec<(std::net::Ipv6Addr, u32)>,
+    ) -> Self {
+        let (v4, v4_ttl) = v4.into_iter().unzip();
+        let (v6, v6_ttl) = v6.into_iter().unzip();
+        ResolvedAddrs {
+            v4,
+            v4_ttl,
+            v6,
+            v6_ttl,
+        }
+    }
+
     pub fn v4(&self) -> &[std::net::Ipv4Addr] {
         &self.v4
     }
@@ -67,6 +87,20 @@ impl ResolvedAddrs {
     pub fn v6(&self) -> &[std::net::Ipv6Addr] {
         &self.v6
     }
+
+    /// TTLs, in seconds, parallel to [`ResolvedAddrs::v4`]. Zero when the
+    /// resolution backend (e.g. [`SystemResolver`](crate::dns::resolver::SystemResolver))
+    /// doesn't expose record TTLs.
+    pub fn v4_ttls(&self) -> &[u32] {
+        &self.v4_ttl
+    }
+
+    /// TTLs, in seconds, parallel to [`ResolvedAddrs::v6`]. Zero when the
+    /// resolution backend (e.g. [`SystemResolver`](crate::dns::resolver::SystemResolver))
+    /// doesn't expose record TTLs.
+    pub fn v6_ttls(&self) -> &[u32] {
+        &self.v6_ttl
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -76,8 +110,13 @@ pub struct DnsHostname {
 
 impl DnsHostname {
     pub fn new(hostname: &str) -> Result<Self, DnsError> {
-        let name = rr::Name::from_str(hostname).map_err(|_| DnsError::InvalidInput)?;
-        let hostname = name.to_string();
+        let ascii = if hostname.is_ascii() {
+            hostname.to_string()
+        } else {
+            idna::domain_to_ascii(hostname).map_err(|_| DnsError::InvalidInput)?
+        };
+        let name = rr::Name::from_str(&ascii).map_err(|_| DnsError::InvalidInput)?;
+        let hostname = name.to_ascii();
         if is_valid_dns_host(&hostname) {
             Ok(DnsHostname {
                 hostname: Arc::new(hostname),
@@ -91,6 +130,14 @@ impl DnsHostname {
         &self.hostname
     }
 
+    /// Returns the Unicode (IDNA ToUnicode) form of this hostname. For a
+    /// hostname with no internationalized labels this is
+    /// [`DnsHostname::as_str`] with ASCII letters lowercased.
+    pub fn as_unicode(&self) -> String {
+        let (unicode, _) = idna::domain_to_unicode(&self.hostname);
+        unicode
+    }
+
     fn to_socket_addrs(&self) -> Result<std::vec::IntoIter<std::net::SocketAddr>, std::io::Error> {
         format!("{}:0", self.hostname).to_socket_addrs()
     }
@@ -108,9 +155,13 @@ impl DnsHostname {
                 }
             }
         }
+        let v4_ttl = vec![0; v4.len()];
+        let v6_ttl = vec![0; v6.len()];
         Ok(ResolvedAddrs {
-            v4: v4,
-            v6: v6,
+            v4,
+            v4_ttl,
+            v6,
+            v6_ttl,
         })
     }
 
@@ -0,0 +1,212 @@
+//! A configurable DNS resolver backend built on `hickory-resolver`, plus a
+//! [`SystemResolver`] fallback that keeps the original getaddrinfo-based
+//! behavior from [`DnsHostname`](super::DnsHostname).
+
+#[cfg(feature = "tokio")]
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+#[cfg(feature = "tokio")]
+use hickory_resolver::config::{
+    NameServerConfig, Protocol, ResolverConfig, ResolverOpts,
+};
+#[cfg(feature = "tokio")]
+use hickory_resolver::TokioAsyncResolver;
+
+use super::{DnsError, DnsHostname, ResolvedAddrs};
+
+/// Transport used to reach the configured name servers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    Udp,
+    Tcp,
+    Tls,
+    Https,
+}
+
+/// Builder for a [`Resolver`]. When no server is added, `build` uses
+/// `ResolverConfig::default()` (Google Public DNS), not the system-configured
+/// name servers. Defaults to UDP with a 5 second timeout, mirroring
+/// `ResolverOpts`'s own defaults.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone)]
+pub struct ResolverBuilder {
+    servers: Vec<SocketAddr>,
+    transport: Transport,
+    search_domains: Vec<String>,
+    edns: bool,
+    timeout: Duration,
+}
+
+#[cfg(feature = "tokio")]
+impl ResolverBuilder {
+    pub fn new() -> Self {
+        ResolverBuilder {
+            servers: Vec::new(),
+            transport: Transport::Udp,
+            search_domains: Vec::new(),
+            edns: false,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// Adds an upstream name server.
+    pub fn server(mut self, addr: SocketAddr) -> Self {
+        self.servers.push(addr);
+        self
+    }
+
+    /// Sets the transport used to reach the upstream name servers.
+    pub fn transport(mut self, transport: Transport) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Adds a domain to the DNS search list.
+    pub fn search_domain(mut self, domain: impl Into<String>) -> Self {
+        self.search_domains.push(domain.into());
+        self
+    }
+
+    /// Enables or disables EDNS.
+    pub fn edns(mut self, enabled: bool) -> Self {
+        self.edns = enabled;
+        self
+    }
+
+    /// Sets the per-query timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn build(self) -> Result<Resolver, DnsError> {
+        let protocol = match self.transport {
+            Transport::Udp => Protocol::Udp,
+            Transport::Tcp => Protocol::Tcp,
+            Transport::Tls => Protocol::Tls,
+            Transport::Https => Protocol::Https,
+        };
+
+        let mut config = if self.servers.is_empty() {
+            ResolverConfig::default()
+        } else {
+            ResolverConfig::new()
+        };
+
+        for domain in &self.search_domains {
+            let name = domain.parse().map_err(|_| DnsError::InvalidInput)?;
+            config.add_search(name);
+        }
+
+        for addr in &self.servers {
+            config.add_name_server(NameServerConfig::new(*addr, protocol));
+        }
+
+        let mut opts = ResolverOpts::default();
+        opts.edns0 = self.edns;
+        opts.timeout = self.timeout;
+
+        let inner = TokioAsyncResolver::tokio(config, opts);
+        Ok(Resolver { inner })
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Default for ResolverBuilder {
+    fn default() -> Self {
+        ResolverBuilder::new()
+    }
+}
+
+/// A configurable resolver backed by `hickory-resolver`, giving control over
+/// upstream servers, transport, search domains, EDNS, and timeout that the
+/// system getaddrinfo path doesn't expose.
+#[cfg(feature = "tokio")]
+#[derive(Clone)]
+pub struct Resolver {
+    inner: TokioAsyncResolver,
+}
+
+#[cfg(feature = "tokio")]
+impl Resolver {
+    pub fn builder() -> ResolverBuilder {
+        ResolverBuilder::new()
+    }
+
+    /// Resolves A records, each paired with its TTL in seconds.
+    pub async fn resolve_a(
+        &self,
+        hostname: &DnsHostname,
+    ) -> Result<Vec<(Ipv4Addr, u32)>, DnsError> {
+        let lookup = self
+            .inner
+            .ipv4_lookup(hostname.as_str())
+            .await
+            .map_err(|_| DnsError::ProtocolError)?;
+        Ok(lookup
+            .as_lookup()
+            .record_iter()
+            .filter_map(|record| {
+                let ip = record.data().and_then(|data| data.as_a()).map(|a| a.0)?;
+                Some((ip, record.ttl()))
+            })
+            .collect())
+    }
+
+    /// Resolves AAAA records, each paired with its TTL in seconds.
+    pub async fn resolve_aaaa(
+        &self,
+        hostname: &DnsHostname,
+    ) -> Result<Vec<(Ipv6Addr, u32)>, DnsError> {
+        let lookup = self
+            .inner
+            .ipv6_lookup(hostname.as_str())
+            .await
+            .map_err(|_| DnsError::ProtocolError)?;
+        Ok(lookup
+            .as_lookup()
+            .record_iter()
+            .filter_map(|record| {
+                let ip = record.data().and_then(|data| data.as_aaaa()).map(|a| a.0)?;
+                Some((ip, record.ttl()))
+            })
+            .collect())
+    }
+
+    /// Resolves both A and AAAA records into a single [`ResolvedAddrs`],
+    /// with per-record TTLs preserved.
+    pub async fn resolve(&self, hostname: &DnsHostname) -> Result<ResolvedAddrs, DnsError> {
+        let v4 = self.resolve_a(hostname).await.unwrap_or_default();
+        let v6 = self.resolve_aaaa(hostname).await.unwrap_or_default();
+        if v4.is_empty() && v6.is_empty() {
+            return Err(DnsError::ProtocolError);
+        }
+        Ok(ResolvedAddrs::from_records(v4, v6))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl std::fmt::Debug for Resolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Resolver").finish_non_exhaustive()
+    }
+}
+
+/// The original getaddrinfo-based resolution path, kept available for
+/// callers who want system-configured resolution rather than
+/// [`Resolver`]'s explicit configuration.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+impl SystemResolver {
+    pub fn resolve_blocking(&self, hostname: &DnsHostname) -> Result<ResolvedAddrs, DnsError> {
+        hostname.resolve_blocking()
+    }
+
+    #[cfg(feature = "tokio")]
+    pub async fn resolve(&self, hostname: &DnsHostname) -> Result<ResolvedAddrs, DnsError> {
+        DnsHostname::resolve(hostname).await
+    }
+}
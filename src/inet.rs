@@ -78,4 +78,129 @@ impl InetTarget {
             InetTarget::V6(v6) => v6.net().map(|n| n.into()),
         }
     }
+
+    /// Returns true if `other`'s network (or host) address falls entirely
+    /// inside `self`'s prefix. Always false across address families.
+    pub fn contains(&self, other: &Self) -> bool {
+        match (self, other) {
+            (InetTarget::V4(a), InetTarget::V4(b)) => a.contains(b),
+            (InetTarget::V6(a), InetTarget::V6(b)) => a.contains(b),
+            _ => false,
+        }
+    }
+
+    /// Iterates every address in the range, see
+    /// [`Ipv4Target::hosts`](crate::ipv4::Ipv4Target::hosts) and
+    /// [`Ipv6Target::hosts`](crate::ipv6::Ipv6Target::hosts).
+    pub fn hosts(&self) -> InetHosts {
+        match self {
+            InetTarget::V4(v4) => InetHosts::V4(v4.hosts()),
+            InetTarget::V6(v6) => InetHosts::V6(v6.hosts()),
+        }
+    }
+
+    /// Encodes this target as a length-discriminated byte string, see
+    /// [`Ipv4Target::to_bytes`](crate::ipv4::Ipv4Target::to_bytes) and
+    /// [`Ipv6Target::to_bytes`](crate::ipv6::Ipv6Target::to_bytes).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            InetTarget::V4(v4) => v4.to_bytes(),
+            InetTarget::V6(v6) => v6.to_bytes(),
+        }
+    }
+
+    /// Decodes a target produced by [`InetTarget::to_bytes`]. Dispatches on
+    /// length: 4/5 bytes decode as IPv4, 16/17 bytes decode as IPv6, any
+    /// other length is [`InetError::Other`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InetError> {
+        match bytes.len() {
+            4 | 5 => crate::ipv4::Ipv4Target::from_bytes(bytes)
+                .map(InetTarget::V4)
+                .map_err(InetError::V4),
+            16 | 17 => crate::ipv6::Ipv6Target::from_bytes(bytes)
+                .map(InetTarget::V6)
+                .map_err(InetError::V6),
+            _ => Err(InetError::Other),
+        }
+    }
+
+    /// Subdivides this target into every `Target` of prefix length
+    /// `new_prefix_len`.
+    pub fn subnets(&self, new_prefix_len: u8) -> Result<InetSubnets, InetError> {
+        match self {
+            InetTarget::V4(v4) => v4
+                .subnets(new_prefix_len)
+                .map(InetSubnets::V4)
+                .map_err(InetError::V4),
+            InetTarget::V6(v6) => v6
+                .subnets(new_prefix_len)
+                .map(InetSubnets::V6)
+                .map_err(InetError::V6),
+        }
+    }
+}
+
+/// Lazy iterator over every host address in an [`InetTarget`]'s range.
+#[derive(Debug, Clone)]
+pub enum InetHosts {
+    V4(crate::ipv4::Ipv4Hosts),
+    V6(crate::ipv6::Ipv6Hosts),
+}
+
+impl Iterator for InetHosts {
+    type Item = std::net::IpAddr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            InetHosts::V4(it) => it.next().map(std::net::IpAddr::V4),
+            InetHosts::V6(it) => it.next().map(std::net::IpAddr::V6),
+        }
+    }
+}
+
+/// Lazy iterator over the subnets of an [`InetTarget`] at a given prefix
+/// length, as produced by [`InetTarget::subnets`].
+#[derive(Debug, Clone)]
+pub enum InetSubnets {
+    V4(crate::ipv4::Ipv4Subnets),
+    V6(crate::ipv6::Ipv6Subnets),
+}
+
+impl Iterator for InetSubnets {
+    type Item = InetTarget;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            InetSubnets::V4(it) => it.next().map(InetTarget::V4),
+            InetSubnets::V6(it) => it.next().map(InetTarget::V6),
+        }
+    }
+}
+
+/// Collapses a list of networks and hosts into the minimal set of covering
+/// CIDRs, see [`ipv4::aggregate`](crate::ipv4::aggregate) and
+/// [`ipv6::aggregate`](crate::ipv6::aggregate). IPv4 and IPv6 targets are
+/// aggregated independently; the v4 results are returned before the v6
+/// results.
+pub fn aggregate(targets: &[InetTarget]) -> Vec<InetTarget> {
+    let v4: Vec<crate::ipv4::Ipv4Target> = targets
+        .iter()
+        .filter_map(|target| match target {
+            InetTarget::V4(v4) => Some(*v4),
+            InetTarget::V6(_) => None,
+        })
+        .collect();
+    let v6: Vec<crate::ipv6::Ipv6Target> = targets
+        .iter()
+        .filter_map(|target| match target {
+            InetTarget::V4(_) => None,
+            InetTarget::V6(v6) => Some(*v6),
+        })
+        .collect();
+
+    crate::ipv4::aggregate(&v4)
+        .into_iter()
+        .map(InetTarget::V4)
+        .chain(crate::ipv6::aggregate(&v6).into_iter().map(InetTarget::V6))
+        .collect()
 }
@@ -23,6 +23,19 @@ fn ipv4_network_address(ip: RawIpv4Addr, prefix_len: u8) -> RawIpv4Addr {
     addr
 }
 
+/// Converts a dotted-decimal netmask to a prefix length, rejecting masks
+/// where a 0 bit precedes a 1 bit.
+fn ipv4_mask_to_prefix(mask: RawIpv4Addr) -> Result<u8, Ipv4Error> {
+    let mask = u32::from_be_bytes(mask);
+    let prefix_len = mask.leading_ones();
+    let expected = (!0u32).checked_shl(32 - prefix_len).unwrap_or(0);
+    if mask == expected {
+        Ok(prefix_len as u8)
+    } else {
+        Err(Ipv4Error)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Ipv4Error;
 
@@ -73,6 +86,240 @@ impl Ipv4Target {
             ipnet::Ipv4Net::new(Ipv4Addr::from(network), prefix_len).unwrap()
         })
     }
+
+    /// Prefix length, treating a bare host (no prefix) as a /32.
+    pub fn network_len(&self) -> u8 {
+        self.prefix_len.unwrap_or(32)
+    }
+
+    /// Number of addresses covered by this target.
+    pub fn num_addresses(&self) -> u64 {
+        1u64 << (32 - self.network_len() as u32)
+    }
+
+    /// The subnet mask, e.g. `255.255.255.0` for a /24.
+    pub fn netmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from(ipv4_subnet_mask(self.network_len()))
+    }
+
+    /// The inverse of [`Ipv4Target::netmask`], e.g. `0.0.0.255` for a /24.
+    pub fn hostmask(&self) -> Ipv4Addr {
+        let mask = ipv4_subnet_mask(self.network_len());
+        let mut host = [0u8; 4];
+        for i in 0..4 {
+            host[i] = !mask[i];
+        }
+        Ipv4Addr::from(host)
+    }
+
+    /// Last address in the network.
+    pub fn broadcast(&self) -> Ipv4Addr {
+        let mask = u32::from_be_bytes(ipv4_subnet_mask(self.network_len()));
+        let network = u32::from_be_bytes(self.ip) & mask;
+        Ipv4Addr::from(network | !mask)
+    }
+
+    /// Returns true if `other`'s network (or host) address falls entirely
+    /// inside `self`'s prefix. A target with no prefix is treated as a /32.
+    pub fn contains(&self, other: &Self) -> bool {
+        let self_prefix_len = self.prefix_len.unwrap_or(32);
+        let other_prefix_len = other.prefix_len.unwrap_or(32);
+        if other_prefix_len < self_prefix_len {
+            return false;
+        }
+        let network = ipv4_network_address(self.ip, self_prefix_len);
+        let other_network = ipv4_network_address(other.ip, self_prefix_len);
+        network == other_network
+    }
+
+    /// Iterates every address in the range. The network and broadcast
+    /// addresses are excluded for prefixes of /30 or shorter; for /31 and
+    /// /32 (and bare hosts) every address is yielded.
+    pub fn hosts(&self) -> Ipv4Hosts {
+        let addr = u32::from_be_bytes(self.ip);
+        match self.prefix_len {
+            None => Ipv4Hosts {
+                next: Some(addr),
+                last: addr,
+            },
+            Some(prefix_len) => {
+                let mask = u32::from_be_bytes(ipv4_subnet_mask(prefix_len));
+                let network = addr & mask;
+                let broadcast = network | !mask;
+                if prefix_len >= 31 {
+                    Ipv4Hosts {
+                        next: Some(network),
+                        last: broadcast,
+                    }
+                } else {
+                    Ipv4Hosts {
+                        next: Some(network + 1),
+                        last: broadcast - 1,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Encodes this target as a length-discriminated byte string: 4 bytes
+    /// for a bare host, or 4 address bytes plus a trailing prefix-length
+    /// octet when a prefix is set.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.ip.to_vec();
+        if let Some(prefix_len) = self.prefix_len {
+            bytes.push(prefix_len);
+        }
+        bytes
+    }
+
+    /// Decodes a target produced by [`Ipv4Target::to_bytes`]. Accepts 4
+    /// bytes (bare host) or 5 bytes (host plus prefix-length octet); any
+    /// other length, or an invalid/non-network prefix, is an error.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Ipv4Error> {
+        match bytes.len() {
+            4 => {
+                let mut ip = [0u8; 4];
+                ip.copy_from_slice(bytes);
+                Ipv4Target::new(Ipv4Addr::from(ip), None)
+            }
+            5 => {
+                let mut ip = [0u8; 4];
+                ip.copy_from_slice(&bytes[..4]);
+                Ipv4Target::new(Ipv4Addr::from(ip), Some(bytes[4]))
+            }
+            _ => Err(Ipv4Error),
+        }
+    }
+
+    /// Subdivides this target into every `Target` of prefix length
+    /// `new_prefix_len`. Errors if `new_prefix_len` is shorter than the
+    /// current prefix or longer than 32.
+    pub fn subnets(&self, new_prefix_len: u8) -> Result<Ipv4Subnets, Ipv4Error> {
+        let self_prefix_len = self.prefix_len.unwrap_or(32);
+        if new_prefix_len < self_prefix_len || new_prefix_len > 32 {
+            return Err(Ipv4Error);
+        }
+        let mask = u32::from_be_bytes(ipv4_subnet_mask(self_prefix_len));
+        let network = u32::from_be_bytes(self.ip) & mask;
+        let broadcast = network | !mask;
+        let new_mask = u32::from_be_bytes(ipv4_subnet_mask(new_prefix_len));
+        let last = broadcast & new_mask;
+        Ok(Ipv4Subnets {
+            next: Some(network),
+            last,
+            new_prefix_len,
+        })
+    }
+}
+
+/// Lazy iterator over every host address in an [`Ipv4Target`]'s range.
+#[derive(Debug, Clone)]
+pub struct Ipv4Hosts {
+    next: Option<u32>,
+    last: u32,
+}
+
+impl Iterator for Ipv4Hosts {
+    type Item = Ipv4Addr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = if current == self.last {
+            None
+        } else {
+            Some(current + 1)
+        };
+        Some(Ipv4Addr::from(current))
+    }
+}
+
+/// Lazy iterator over the subnets of an [`Ipv4Target`] at a given prefix
+/// length, as produced by [`Ipv4Target::subnets`].
+#[derive(Debug, Clone)]
+pub struct Ipv4Subnets {
+    next: Option<u32>,
+    last: u32,
+    new_prefix_len: u8,
+}
+
+impl Iterator for Ipv4Subnets {
+    type Item = Ipv4Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = if current == self.last {
+            None
+        } else {
+            let step = 1u64 << (32 - self.new_prefix_len as u32);
+            Some((current as u64 + step) as u32)
+        };
+        Some(Ipv4Target {
+            ip: current.to_be_bytes(),
+            prefix_len: Some(self.new_prefix_len),
+        })
+    }
+}
+
+/// Collapses a list of networks and hosts into the minimal set of covering
+/// CIDRs: sorts by (network address, prefix length), drops any range fully
+/// contained in a preceding one, then repeatedly merges adjacent sibling
+/// prefixes that share a parent until no more merges are possible. The
+/// result is sorted and non-overlapping.
+pub fn aggregate(targets: &[Ipv4Target]) -> Vec<Ipv4Target> {
+    let mut ranges: Vec<(u32, u8)> = targets
+        .iter()
+        .map(|target| {
+            let prefix_len = target.network_len();
+            let mask = u32::from_be_bytes(ipv4_subnet_mask(prefix_len));
+            (u32::from_be_bytes(target.ip) & mask, prefix_len)
+        })
+        .collect();
+    ranges.sort_unstable();
+
+    let mut kept: Vec<(u32, u8)> = Vec::with_capacity(ranges.len());
+    for (network, prefix_len) in ranges {
+        let contained = kept.last().is_some_and(|&(prev_network, prev_prefix_len)| {
+            let mask = u32::from_be_bytes(ipv4_subnet_mask(prev_prefix_len));
+            prefix_len >= prev_prefix_len && network & mask == prev_network
+        });
+        if !contained {
+            kept.push((network, prefix_len));
+        }
+    }
+
+    loop {
+        let mut merged: Vec<(u32, u8)> = Vec::with_capacity(kept.len());
+        let mut did_merge = false;
+        let mut i = 0;
+        while i < kept.len() {
+            if let Some(&(next_network, next_prefix_len)) = kept.get(i + 1) {
+                let (network, prefix_len) = kept[i];
+                if prefix_len > 0 && prefix_len == next_prefix_len {
+                    let parent_mask = u32::from_be_bytes(ipv4_subnet_mask(prefix_len - 1));
+                    let sibling_bit = 1u32 << (32 - prefix_len as u32);
+                    if network & parent_mask == network && next_network == network | sibling_bit {
+                        merged.push((network, prefix_len - 1));
+                        did_merge = true;
+                        i += 2;
+                        continue;
+                    }
+                }
+            }
+            merged.push(kept[i]);
+            i += 1;
+        }
+        kept = merged;
+        if !did_merge {
+            break;
+        }
+    }
+
+    kept.into_iter()
+        .map(|(network, prefix_len)| Ipv4Target {
+            ip: network.to_be_bytes(),
+            prefix_len: Some(prefix_len),
+        })
+        .collect()
 }
 
 impl Display for Ipv4Target {
@@ -91,14 +338,21 @@ impl FromStr for Ipv4Target {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parts = s.split('/');
         let ip = parts.next().ok_or(Ipv4Error)?;
-        let prefix_len = parts.next().map(|s| s.parse().ok()).flatten();
-        match prefix_len {
-            Some(prefix_len) if prefix_len > 32 => return Err(Ipv4Error),
-            _ => (),
-        }
+        let suffix = parts.next();
         if parts.next().is_some() {
             return Err(Ipv4Error);
         }
+        let prefix_len = match suffix {
+            None => None,
+            Some(suffix) => match suffix.parse::<u8>() {
+                Ok(prefix_len) if prefix_len <= 32 => Some(prefix_len),
+                Ok(_) => return Err(Ipv4Error),
+                Err(_) => {
+                    let mask: Ipv4Addr = suffix.parse().map_err(|_| Ipv4Error)?;
+                    Some(ipv4_mask_to_prefix(mask.octets())?)
+                }
+            },
+        };
         let ip: Ipv4Addr = ip.parse().map_err(|_| Ipv4Error)?;
         let ip = ip.octets();
         if let Some(prefix_len) = prefix_len {
@@ -75,6 +75,227 @@ impl Ipv6Target {
             ipnet::Ipv6Net::new(Ipv6Addr::from(network), prefix_len).unwrap()
         })
     }
+
+    /// Prefix length, treating a bare host (no prefix) as a /128.
+    pub fn network_len(&self) -> u8 {
+        self.prefix_len.unwrap_or(128)
+    }
+
+    /// Number of addresses covered by this target, saturating at
+    /// `u128::MAX` (the true count for a /0 is `2^128`, one more than fits).
+    pub fn num_addresses(&self) -> u128 {
+        let host_bits = 128 - self.network_len() as u32;
+        1u128.checked_shl(host_bits).unwrap_or(u128::MAX)
+    }
+
+    /// The subnet mask corresponding to this target's prefix length.
+    pub fn netmask(&self) -> Ipv6Addr {
+        Ipv6Addr::from(ipv6_subnet_mask(self.network_len()))
+    }
+
+    /// The inverse of [`Ipv6Target::netmask`].
+    pub fn hostmask(&self) -> Ipv6Addr {
+        let mask = ipv6_subnet_mask(self.network_len());
+        let mut host = [0u8; 16];
+        for i in 0..16 {
+            host[i] = !mask[i];
+        }
+        Ipv6Addr::from(host)
+    }
+
+    /// Returns true if `other`'s network (or host) address falls entirely
+    /// inside `self`'s prefix. A target with no prefix is treated as a /128.
+    pub fn contains(&self, other: &Self) -> bool {
+        let self_prefix_len = self.prefix_len.unwrap_or(128);
+        let other_prefix_len = other.prefix_len.unwrap_or(128);
+        if other_prefix_len < self_prefix_len {
+            return false;
+        }
+        let network = ipv6_network_address(self.ip, self_prefix_len);
+        let other_network = ipv6_network_address(other.ip, self_prefix_len);
+        network == other_network
+    }
+
+    /// Iterates every address in the range. Unlike IPv4, IPv6 has no
+    /// reserved network/broadcast address, so every address is yielded.
+    pub fn hosts(&self) -> Ipv6Hosts {
+        let addr = u128::from_be_bytes(self.ip);
+        match self.prefix_len {
+            None => Ipv6Hosts {
+                next: Some(addr),
+                last: addr,
+            },
+            Some(prefix_len) => {
+                let mask = u128::from_be_bytes(ipv6_subnet_mask(prefix_len));
+                let network = addr & mask;
+                let last = network | !mask;
+                Ipv6Hosts {
+                    next: Some(network),
+                    last,
+                }
+            }
+        }
+    }
+
+    /// Encodes this target as a length-discriminated byte string: 16 bytes
+    /// for a bare host, or 16 address bytes plus a trailing prefix-length
+    /// octet when a prefix is set.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.ip.to_vec();
+        if let Some(prefix_len) = self.prefix_len {
+            bytes.push(prefix_len);
+        }
+        bytes
+    }
+
+    /// Decodes a target produced by [`Ipv6Target::to_bytes`]. Accepts 16
+    /// bytes (bare host) or 17 bytes (host plus prefix-length octet); any
+    /// other length, or an invalid/non-network prefix, is an error.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Ipv6Error> {
+        match bytes.len() {
+            16 => {
+                let mut ip = [0u8; 16];
+                ip.copy_from_slice(bytes);
+                Ipv6Target::new(Ipv6Addr::from(ip), None)
+            }
+            17 => {
+                let mut ip = [0u8; 16];
+                ip.copy_from_slice(&bytes[..16]);
+                Ipv6Target::new(Ipv6Addr::from(ip), Some(bytes[16]))
+            }
+            _ => Err(Ipv6Error),
+        }
+    }
+
+    /// Subdivides this target into every `Target` of prefix length
+    /// `new_prefix_len`. Errors if `new_prefix_len` is shorter than the
+    /// current prefix or longer than 128.
+    pub fn subnets(&self, new_prefix_len: u8) -> Result<Ipv6Subnets, Ipv6Error> {
+        let self_prefix_len = self.prefix_len.unwrap_or(128);
+        if new_prefix_len < self_prefix_len || new_prefix_len > 128 {
+            return Err(Ipv6Error);
+        }
+        let mask = u128::from_be_bytes(ipv6_subnet_mask(self_prefix_len));
+        let network = u128::from_be_bytes(self.ip) & mask;
+        let last_addr = network | !mask;
+        let new_mask = u128::from_be_bytes(ipv6_subnet_mask(new_prefix_len));
+        let last = last_addr & new_mask;
+        Ok(Ipv6Subnets {
+            next: Some(network),
+            last,
+            new_prefix_len,
+        })
+    }
+}
+
+/// Lazy iterator over every host address in an [`Ipv6Target`]'s range.
+#[derive(Debug, Clone)]
+pub struct Ipv6Hosts {
+    next: Option<u128>,
+    last: u128,
+}
+
+impl Iterator for Ipv6Hosts {
+    type Item = Ipv6Addr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = if current == self.last {
+            None
+        } else {
+            Some(current + 1)
+        };
+        Some(Ipv6Addr::from(current))
+    }
+}
+
+/// Lazy iterator over the subnets of an [`Ipv6Target`] at a given prefix
+/// length, as produced by [`Ipv6Target::subnets`].
+#[derive(Debug, Clone)]
+pub struct Ipv6Subnets {
+    next: Option<u128>,
+    last: u128,
+    new_prefix_len: u8,
+}
+
+impl Iterator for Ipv6Subnets {
+    type Item = Ipv6Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = if current == self.last {
+            None
+        } else {
+            let step = 1u128 << (128 - self.new_prefix_len as u32);
+            Some(current + step)
+        };
+        Some(Ipv6Target {
+            ip: current.to_be_bytes(),
+            prefix_len: Some(self.new_prefix_len),
+        })
+    }
+}
+
+/// Collapses a list of networks and hosts into the minimal set of covering
+/// CIDRs: sorts by (network address, prefix length), drops any range fully
+/// contained in a preceding one, then repeatedly merges adjacent sibling
+/// prefixes that share a parent until no more merges are possible. The
+/// result is sorted and non-overlapping.
+pub fn aggregate(targets: &[Ipv6Target]) -> Vec<Ipv6Target> {
+    let mut ranges: Vec<(u128, u8)> = targets
+        .iter()
+        .map(|target| {
+            let prefix_len = target.network_len();
+            let mask = u128::from_be_bytes(ipv6_subnet_mask(prefix_len));
+            (u128::from_be_bytes(target.ip) & mask, prefix_len)
+        })
+        .collect();
+    ranges.sort_unstable();
+
+    let mut kept: Vec<(u128, u8)> = Vec::with_capacity(ranges.len());
+    for (network, prefix_len) in ranges {
+        let contained = kept.last().is_some_and(|&(prev_network, prev_prefix_len)| {
+            let mask = u128::from_be_bytes(ipv6_subnet_mask(prev_prefix_len));
+            prefix_len >= prev_prefix_len && network & mask == prev_network
+        });
+        if !contained {
+            kept.push((network, prefix_len));
+        }
+    }
+
+    loop {
+        let mut merged: Vec<(u128, u8)> = Vec::with_capacity(kept.len());
+        let mut did_merge = false;
+        let mut i = 0;
+        while i < kept.len() {
+            if let Some(&(next_network, next_prefix_len)) = kept.get(i + 1) {
+                let (network, prefix_len) = kept[i];
+                if prefix_len > 0 && prefix_len == next_prefix_len {
+                    let parent_mask = u128::from_be_bytes(ipv6_subnet_mask(prefix_len - 1));
+                    let sibling_bit = 1u128 << (128 - prefix_len as u32);
+                    if network & parent_mask == network && next_network == network | sibling_bit {
+                        merged.push((network, prefix_len - 1));
+                        did_merge = true;
+                        i += 2;
+                        continue;
+                    }
+                }
+            }
+            merged.push(kept[i]);
+            i += 1;
+        }
+        kept = merged;
+        if !did_merge {
+            break;
+        }
+    }
+
+    kept.into_iter()
+        .map(|(network, prefix_len)| Ipv6Target {
+            ip: network.to_be_bytes(),
+            prefix_len: Some(prefix_len),
+        })
+        .collect()
 }
 
 impl Display for Ipv6Target {